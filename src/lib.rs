@@ -84,7 +84,7 @@ mod tests {
         // 1.0  3.0
         // 2.0  4.0
         // 5.0, 3.5
-        let a = Matrix::<3, 2>::from_array([1.0, 2.0, 5.0, 3.0, 4.0, 3.5]);
+        let a = Matrix::<f64, 3, 2>::from_array([1.0, 2.0, 5.0, 3.0, 4.0, 3.5]);
 
         // Check the columns
         assert_eq!(a.cols()[0], Vector3::from_array([1.0, 2.0, 5.0]));
@@ -107,6 +107,285 @@ mod tests {
         assert_eq!(c, Matrix2::from_array([1.0, 2.0, 3.0, 4.0]));
     }
 
+    #[test]
+    fn matrix_integer_elements() {
+        // Matrix of the form:
+        // 1  2
+        // 3  4
+        let a = Matrix::<i32, 2, 2>::from_array([1, 3, 2, 4]);
+        // Matrix of the form:
+        // 5  6
+        // 7  8
+        let b = Matrix::<i32, 2, 2>::from_array([5, 7, 6, 8]);
+
+        assert_eq!(Matrix::<i32, 2, 2>::from_array([6, 10, 8, 12]), a + b);
+        assert_eq!(Matrix::<i32, 2, 2>::from_array([19, 43, 22, 50]), a * b);
+    }
+
+    #[test]
+    fn matrix_indices() {
+        // 2x2 matrices are indexed in column-major order: (0,0), (1,0), (0,1), (1,1).
+        let a = Matrix2::empty();
+        let indices: Vec<(usize, usize)> = a.indices().collect();
+        assert_eq!(vec![(0, 0), (1, 0), (0, 1), (1, 1)], indices);
+    }
+
+    #[test]
+    fn matrix_iter_and_iter_mut() {
+        // Matrix of the form:
+        // 1.0  2.0
+        // 3.0  4.0
+        let mut a = Matrix2::from_array([1.0, 3.0, 2.0, 4.0]);
+
+        let sum: f64 = a.iter().sum();
+        assert_eq_array_epsilon(&[10.0], &[sum], 0.0001);
+
+        a.iter_mut().for_each(|elem| *elem *= 10.0);
+        assert_eq_array_epsilon(&[10.0, 30.0, 20.0, 40.0], &a.elems, 0.0001);
+    }
+
+    #[test]
+    fn matrix_iter_rows_and_cols() {
+        // Matrix of the form:
+        // 1.0  2.0
+        // 3.0  4.0
+        let a = Matrix2::from_array([1.0, 3.0, 2.0, 4.0]);
+
+        let rows: Vec<_> = a.iter_rows().collect();
+        assert_eq!(Matrix::<f64, 1, 2>::from_array([1.0, 2.0]), rows[0]);
+        assert_eq!(Matrix::<f64, 1, 2>::from_array([3.0, 4.0]), rows[1]);
+
+        let cols: Vec<_> = a.iter_cols().collect();
+        assert_eq!(Vector2::from_array([1.0, 3.0]), cols[0]);
+        assert_eq!(Vector2::from_array([2.0, 4.0]), cols[1]);
+    }
+
+    #[test]
+    fn matrix_add_assign() {
+        // Matrix of the form:
+        // 1.0  2.0
+        // 3.0  4.0
+        let mut a = Matrix2::from_array([1.0, 3.0, 2.0, 4.0]);
+        // Matrix of the form:
+        // 0.5  0.5
+        // 0.5  0.5
+        let b = Matrix2::filled(0.5);
+
+        a += b;
+        assert_eq_array_epsilon(&[1.5, 3.5, 2.5, 4.5], &a.elems, 0.0001);
+    }
+
+    #[test]
+    fn matrix_sub_assign() {
+        // Matrix of the form:
+        // 1.0  2.0
+        // 3.0  4.0
+        let mut a = Matrix2::from_array([1.0, 3.0, 2.0, 4.0]);
+        // Matrix of the form:
+        // 0.5  0.5
+        // 0.5  0.5
+        let b = Matrix2::filled(0.5);
+
+        a -= b;
+        assert_eq_array_epsilon(&[0.5, 2.5, 1.5, 3.5], &a.elems, 0.0001);
+    }
+
+    #[test]
+    fn matrix_mul_assign() {
+        // Matrix of the form:
+        // 1.0  2.0
+        // 3.0  4.0
+        let mut a = Matrix2::from_array([1.0, 3.0, 2.0, 4.0]);
+
+        a *= 2.0;
+        assert_eq_array_epsilon(&[2.0, 6.0, 4.0, 8.0], &a.elems, 0.0001);
+    }
+
+    #[test]
+    fn matrix_div_and_div_assign() {
+        // Matrix of the form:
+        // 2.0  4.0
+        // 6.0  8.0
+        let a = Matrix2::from_array([2.0, 6.0, 4.0, 8.0]);
+
+        let mut divided = a / 2.0;
+        assert_eq_array_epsilon(&[1.0, 3.0, 2.0, 4.0], &divided.elems, 0.0001);
+
+        divided /= 2.0;
+        assert_eq_array_epsilon(&[0.5, 1.5, 1.0, 2.0], &divided.elems, 0.0001);
+    }
+
+    #[test]
+    fn matrix_neg() {
+        // Matrix of the form:
+        // 1.0  -2.0
+        // -3.0  4.0
+        let a = Matrix2::from_array([1.0, -3.0, -2.0, 4.0]);
+
+        assert_eq_array_epsilon(&[-1.0, 3.0, 2.0, -4.0], &(-a).elems, 0.0001);
+    }
+
+    #[test]
+    fn matrix_transpose() {
+        // Matrix of the form:
+        // 1.0  3.0
+        // 2.0  4.0
+        // 5.0  3.5
+        let a = Matrix::<f64, 3, 2>::from_array([1.0, 2.0, 5.0, 3.0, 4.0, 3.5]);
+
+        // Matrix of the form:
+        // 1.0  2.0  5.0
+        // 3.0  4.0  3.5
+        let transposed = a.transpose();
+        assert_eq!(
+            Matrix::<f64, 2, 3>::from_array([1.0, 3.0, 2.0, 4.0, 5.0, 3.5]),
+            transposed
+        );
+    }
+
+    #[test]
+    fn matrix_map() {
+        // Matrix of the form:
+        // 1.0  3.0
+        // 2.0  4.0
+        let a = Matrix2::from_array([1.0, 2.0, 3.0, 4.0]);
+
+        let doubled = a.map(|elem| elem * 2.0);
+        assert_eq_array_epsilon(&[2.0, 4.0, 6.0, 8.0], &doubled.elems, 0.0001);
+    }
+
+    #[test]
+    fn matrix_from_fn() {
+        // Build a 3x2 matrix holding 1.0 through 6.0 in column-major order from a generator
+        // function:
+        // 1.0  4.0
+        // 2.0  5.0
+        // 3.0  6.0
+        let a = Matrix::<f64, 3, 2>::from_fn(|row, col| (row + col * 3) as f64 + 1.0);
+        assert_eq!(
+            Matrix::<f64, 3, 2>::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+            a
+        );
+    }
+
+    #[test]
+    fn matrix_minor() {
+        // Matrix of the form:
+        // 1  2  3
+        // 4  5  6
+        // 7  8  9
+        let a = Matrix::<i32, 3, 3>::from_array([1, 4, 7, 2, 5, 8, 3, 6, 9]);
+
+        // Deleting row 0 and column 0 leaves:
+        // 5  6
+        // 8  9
+        assert_eq!(Matrix::<i32, 2, 2>::from_array([5, 8, 6, 9]), a.minor(0, 0));
+    }
+
+    #[test]
+    fn matrix_cofactor_determinant() {
+        // Matrix of the form:
+        // 6  1  1
+        // 4  -2  5
+        // 2  8  7
+        let a = Matrix::<i32, 3, 3>::from_array([6, 4, 2, 1, -2, 8, 1, 5, 7]);
+        assert_eq!(-306, a.cofactor_determinant());
+    }
+
+    #[test]
+    fn matrix_adjugate() {
+        // Matrix of the form:
+        // 1  2
+        // 3  4
+        let a = Matrix::<i32, 2, 2>::from_array([1, 3, 2, 4]);
+
+        // The adjugate of a 2x2 matrix [[a,b],[c,d]] is [[d,-b],[-c,a]].
+        assert_eq!(Matrix::<i32, 2, 2>::from_array([4, -3, -2, 1]), a.adjugate());
+    }
+
+    #[test]
+    fn matrix_determinant() {
+        // Matrix of the form:
+        // 4.0  3.0
+        // 6.0  3.0
+        let a = Matrix2::from_array([4.0, 6.0, 3.0, 3.0]);
+        assert!((a.determinant() - -6.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn matrix_determinant_singular() {
+        // Matrix of the form:
+        // 1.0  2.0
+        // 2.0  4.0
+        let a = Matrix2::from_array([1.0, 2.0, 2.0, 4.0]);
+        assert_eq!(0.0, a.determinant());
+    }
+
+    #[test]
+    fn matrix_inverse() {
+        // Matrix of the form:
+        // 4.0  7.0
+        // 2.0  6.0
+        let a = Matrix2::from_array([4.0, 2.0, 7.0, 6.0]);
+        let inverse = a.inverse().unwrap();
+
+        // A·A⁻¹ should be the identity matrix.
+        let product = a * inverse;
+        assert_eq_array_epsilon(&Matrix2::identity().elems, &product.elems, 0.0001);
+    }
+
+    #[test]
+    fn matrix_inverse_singular() {
+        // Matrix of the form:
+        // 1.0  2.0
+        // 2.0  4.0
+        let a = Matrix2::from_array([1.0, 2.0, 2.0, 4.0]);
+        assert_eq!(None, a.inverse());
+    }
+
+    #[test]
+    fn matrix_frobenius_and_max_abs_norm() {
+        // Matrix of the form:
+        // 3.0  0.0
+        // 4.0  0.0
+        let a = Matrix2::from_array([3.0, 4.0, 0.0, 0.0]);
+
+        assert!((a.frobenius_norm() - 5.0).abs() < 0.0001);
+        assert!((a.max_abs_norm() - 4.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn vector_lp_norm() {
+        // Vector of the form:
+        // 3.0
+        // -4.0
+        let a = Vector2::from_array([3.0, -4.0]);
+
+        assert!((a.lp_norm(1.0) - 7.0).abs() < 0.0001);
+        assert!((a.lp_norm(2.0) - 5.0).abs() < 0.0001);
+        assert!((a.lp_norm(f64::INFINITY) - 4.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn vector_normalize() {
+        // Vector of the form:
+        // 3.0
+        // 4.0
+        let a = Vector2::from_array([3.0, 4.0]);
+        let normalized = a.normalize().unwrap();
+
+        assert_eq_array_epsilon(&[0.6, 0.8], &normalized.elems, 0.0001);
+        assert_eq!(None, Vector2::empty().normalize());
+    }
+
+    #[test]
+    fn vector_distance() {
+        let a = Vector2::from_array([1.0, 1.0]);
+        let b = Vector2::from_array([4.0, 5.0]);
+
+        assert!((a.distance(&b) - 5.0).abs() < 0.0001);
+    }
+
     // Utility function to check if the given arrays' values are within `epsilon` of each other and
     // panic if they aren't
     fn assert_eq_array_epsilon<const LEN: usize>(a: &[f64; LEN], b: &[f64; LEN], epsilon: f64) {
@@ -1,17 +1,50 @@
-use std::ops::{Add, Index, IndexMut, Mul, Sub};
+#[cfg(feature = "rand")]
+use rand::Rng;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+/// Trait describing the numeric scalar types a [`Matrix`] can be built from.
+pub trait MatrixElement: Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Copy {
+    /// Return the additive identity for this type.
+    fn zero() -> Self;
+    /// Return the multiplicative identity for this type.
+    fn one() -> Self;
+}
+
+// Implement `MatrixElement` for the primitive numeric types using their own `0`/`1` literals.
+macro_rules! impl_matrix_element {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl MatrixElement for $ty {
+                fn zero() -> Self {
+                    0 as $ty
+                }
+
+                fn one() -> Self {
+                    1 as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_matrix_element!(
+    f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
 
 /// Type alias for the array of matrix elements.
-pub type MatrixArray<const ROWS: usize, const COLS: usize> = [f64; ROWS * COLS];
+pub type MatrixArray<T, const ROWS: usize, const COLS: usize> = [T; ROWS * COLS];
 
 /// Type alias for a 2x2 matrix.
-pub type Matrix2 = Matrix<2, 2>;
+pub type Matrix2 = Matrix<f64, 2, 2>;
 /// Type alias for a 3x3 matrix.
-pub type Matrix3 = Matrix<3, 3>;
+pub type Matrix3 = Matrix<f64, 3, 3>;
 /// Type alias for a 4x4 matrix.
-pub type Matrix4 = Matrix<4, 4>;
+pub type Matrix4 = Matrix<f64, 4, 4>;
 
 /// Type alias for a 1-column matrix (column vector).
-pub type Vector<const ROWS: usize> = Matrix<ROWS, 1>;
+pub type Vector<const ROWS: usize> = Matrix<f64, ROWS, 1>;
 
 /// Type alias for a 2-row column vector.
 pub type Vector2 = Vector<2>;
@@ -20,44 +53,46 @@ pub type Vector3 = Vector<3>;
 /// Type alias for a 4-row column vector.
 pub type Vector4 = Vector<4>;
 
-/// A generic constant-size matrix.
+/// A generic constant-size matrix over a numeric element type `T`.
 #[derive(Clone, Copy, Debug)]
-pub struct Matrix<const ROWS: usize, const COLS: usize>
+pub struct Matrix<T, const ROWS: usize, const COLS: usize>
 where
-    [f64; ROWS * COLS]: Sized,
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
 {
     /// The elements of this matrix, stored in column-major order.
-    pub elems: MatrixArray<ROWS, COLS>,
+    pub elems: MatrixArray<T, ROWS, COLS>,
 }
 
-impl<const ROWS: usize, const COLS: usize> Matrix<ROWS, COLS>
+impl<T, const ROWS: usize, const COLS: usize> Matrix<T, ROWS, COLS>
 where
-    [f64; ROWS * COLS]: Sized,
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
 {
     /// Create and return a matrix with the provided value as every element.
-    pub fn from_array(elems: MatrixArray<ROWS, COLS>) -> Self {
+    pub fn from_array(elems: MatrixArray<T, ROWS, COLS>) -> Self {
         Self { elems }
     }
 
     /// Create and return `Some` matrix from the given column-major slice of elements for this
     /// matrix. Returns `None` if the size of `elems` is not `ROWS*COLS`.
-    pub fn from_slice(elems: &[f64]) -> Option<Self> {
-        let elem_array: Option<MatrixArray<ROWS, COLS>> = elems.try_into().ok();
+    pub fn from_slice(elems: &[T]) -> Option<Self> {
+        let elem_array: Option<MatrixArray<T, ROWS, COLS>> = elems.try_into().ok();
         elem_array.map(Self::from_array)
     }
 
     /// Create and return a matrix with the provided value as every element.
-    pub fn filled(elem: f64) -> Self {
+    pub fn filled(elem: T) -> Self {
         Self::from_array([elem; ROWS * COLS])
     }
 
-    /// Create and return a matrix with every element as `0.0f64`.
+    /// Create and return a matrix with every element as `T::zero()`.
     pub fn empty() -> Self {
-        Self::filled(0.0)
+        Self::filled(T::zero())
     }
 
     /// Create and return a matrix with the given value down the matrix diagonal.
-    pub fn identity_elems(elem: f64) -> Self {
+    pub fn identity_elems(elem: T) -> Self {
         let mut matrix = Self::empty();
         for i in 0..ROWS.min(COLS) {
             matrix[(i, i)] = elem;
@@ -67,11 +102,22 @@ where
 
     /// Create and return an identity matrix.
     pub fn identity() -> Self {
-        Self::identity_elems(1.0)
+        Self::identity_elems(T::one())
+    }
+
+    /// Create and return a matrix with element `(row, col)` set to `f(row, col)`.
+    pub fn from_fn<F: Fn(usize, usize) -> T>(f: F) -> Self {
+        let mut matrix = Self::empty();
+        for col in 0..COLS {
+            for row in 0..ROWS {
+                matrix[(row, col)] = f(row, col);
+            }
+        }
+        matrix
     }
 
     /// Create and return a matrix composed of an array of column-vectors.
-    pub fn from_cols(columns: [[f64; ROWS]; COLS]) -> Self {
+    pub fn from_cols(columns: [[T; ROWS]; COLS]) -> Self {
         // SAFETY: This unwrap should be safe because flattening a COLSxROWS array should yield a
         // slice that is ROWS * COLS in size, which allows constructing the matrix from the
         // flattened slice.
@@ -80,44 +126,113 @@ where
 
     /// Return a Vec of columns in this matrix.
     #[allow(clippy::identity_op)]
-    pub fn cols(&self) -> [Matrix<ROWS, 1>; COLS]
+    pub fn cols(&self) -> [Matrix<T, ROWS, 1>; COLS]
     where
-        [f64; ROWS * 1]: Sized,
+        [T; ROWS * 1]: Sized,
     {
-        let mut cols = [Vector::default(); COLS];
+        let mut cols = [Matrix::<T, ROWS, 1>::default(); COLS];
         cols.iter_mut().enumerate().for_each(|(ci, val)| {
             let i = ci * ROWS;
             // SAFETY: The slice can only have a length of `ROWS` so `None` should never be
             // returned.
-            *val = Vector::<ROWS>::from_slice(&self.elems[i..(i + ROWS)]).unwrap();
+            *val = Matrix::<T, ROWS, 1>::from_slice(&self.elems[i..(i + ROWS)]).unwrap();
         });
         cols
     }
 
+    /// Return an array of the rows in this matrix.
+    #[allow(clippy::identity_op)]
+    pub fn rows(&self) -> [Matrix<T, 1, COLS>; ROWS]
+    where
+        [T; 1 * COLS]: Sized,
+    {
+        let mut rows = [Matrix::<T, 1, COLS>::default(); ROWS];
+        rows.iter_mut().enumerate().for_each(|(ri, val)| {
+            for col in 0..COLS {
+                val[(0, col)] = self[(ri, col)];
+            }
+        });
+        rows
+    }
+
     /// Retrieve `Some` reference to the element at the provided at the row and column location or
     /// `None` if the provided element is out of this matrix's bounds.
-    pub fn get(&self, row: usize, col: usize) -> Option<&f64> {
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
         Self::index(row, col).map(|index| &self.elems[index])
     }
 
     /// Retrieve a reference to the element at the provided row and column. This will panic if the
     /// row or column is out of this matrix's bounds.
-    pub fn get_unsafe(&self, row: usize, col: usize) -> &f64 {
+    pub fn get_unsafe(&self, row: usize, col: usize) -> &T {
         &self.elems[Self::index(row, col).unwrap_or_else(|| Self::bounds_panic(row, col))]
     }
 
     /// Retrieve `Some` mutable reference to the element at the provided at the row and column
     /// location or `None` if the provided element is out of this matrix's bounds.
-    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut f64> {
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
         Self::index(row, col).map(|index| &mut self.elems[index])
     }
 
     /// Retrieve a mutable reference to the element at the provided row and column. This will panic
     /// if the row or column is out of this matrix's bounds.
-    pub fn get_mut_unsafe(&mut self, row: usize, col: usize) -> &mut f64 {
+    pub fn get_mut_unsafe(&mut self, row: usize, col: usize) -> &mut T {
         &mut self.elems[Self::index(row, col).unwrap_or_else(|| Self::bounds_panic(row, col))]
     }
 
+    /// Return an iterator over every `(row, col)` pair in this matrix, in column-major order.
+    pub fn indices(&self) -> impl Iterator<Item = (usize, usize)> {
+        (0..COLS).flat_map(move |col| (0..ROWS).map(move |row| (row, col)))
+    }
+
+    /// Return an iterator over references to this matrix's elements, in column-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.elems.iter()
+    }
+
+    /// Return an iterator over mutable references to this matrix's elements, in column-major
+    /// order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.elems.iter_mut()
+    }
+
+    /// Return an iterator over the rows of this matrix, each as a `Matrix<T, 1, COLS>`.
+    #[allow(clippy::identity_op)]
+    pub fn iter_rows(&self) -> impl Iterator<Item = Matrix<T, 1, COLS>>
+    where
+        [T; 1 * COLS]: Sized,
+    {
+        self.rows().into_iter()
+    }
+
+    /// Return an iterator over the columns of this matrix, each as a `Matrix<T, ROWS, 1>`.
+    #[allow(clippy::identity_op)]
+    pub fn iter_cols(&self) -> impl Iterator<Item = Matrix<T, ROWS, 1>>
+    where
+        [T; ROWS * 1]: Sized,
+    {
+        self.cols().into_iter()
+    }
+
+    /// Create and return a new matrix with `f` applied to every element of this matrix.
+    pub fn map<F: Fn(T) -> T>(&self, f: F) -> Self {
+        Self {
+            elems: self.elems.map(f),
+        }
+    }
+
+    /// Create and return the transpose of this matrix, swapping rows and columns so that element
+    /// `(r, c)` of this matrix becomes element `(c, r)` of the result.
+    pub fn transpose(&self) -> Matrix<T, COLS, ROWS>
+    where
+        [T; COLS * ROWS]: Sized,
+    {
+        let mut transposed = Matrix::<T, COLS, ROWS>::empty();
+        for (row, col) in (0..ROWS).flat_map(|row| (0..COLS).map(move |col| (row, col))) {
+            transposed[(col, row)] = self[(row, col)];
+        }
+        transposed
+    }
+
     // Helper function to get the column-major array index of the element at the provided row and
     // column. Returns `None` if the row or column is out of bounds.
     fn index(row: usize, col: usize) -> Option<usize> {
@@ -135,9 +250,10 @@ where
 }
 
 // Implement default as an identity matrix
-impl<const ROWS: usize, const COLS: usize> Default for Matrix<ROWS, COLS>
+impl<T, const ROWS: usize, const COLS: usize> Default for Matrix<T, ROWS, COLS>
 where
-    [f64; ROWS * COLS]: Sized,
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
 {
     fn default() -> Self {
         Self::identity()
@@ -145,11 +261,12 @@ where
 }
 
 // Allow indexing the matrix using a `(row, col)` tuple.
-impl<const ROWS: usize, const COLS: usize> Index<(usize, usize)> for Matrix<ROWS, COLS>
+impl<T, const ROWS: usize, const COLS: usize> Index<(usize, usize)> for Matrix<T, ROWS, COLS>
 where
-    [f64; ROWS * COLS]: Sized,
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
 {
-    type Output = f64;
+    type Output = T;
 
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         self.get_unsafe(index.0, index.1)
@@ -157,9 +274,10 @@ where
 }
 
 // Allow mutable indexing with a `(row, col)` tuple.
-impl<const ROWS: usize, const COLS: usize> IndexMut<(usize, usize)> for Matrix<ROWS, COLS>
+impl<T, const ROWS: usize, const COLS: usize> IndexMut<(usize, usize)> for Matrix<T, ROWS, COLS>
 where
-    [f64; ROWS * COLS]: Sized,
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
 {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
         self.get_mut_unsafe(index.0, index.1)
@@ -167,9 +285,10 @@ where
 }
 
 // Implement partial equality
-impl<const ROWS: usize, const COLS: usize> PartialEq for Matrix<ROWS, COLS>
+impl<T, const ROWS: usize, const COLS: usize> PartialEq for Matrix<T, ROWS, COLS>
 where
-    [f64; ROWS * COLS]: Sized,
+    T: MatrixElement + PartialEq,
+    [T; ROWS * COLS]: Sized,
 {
     fn eq(&self, other: &Self) -> bool {
         self.elems.eq(&other.elems)
@@ -177,45 +296,50 @@ where
 }
 
 // Implement matrix addition
-impl<const ROWS: usize, const COLS: usize> Add<Matrix<ROWS, COLS>> for Matrix<ROWS, COLS>
+impl<T, const ROWS: usize, const COLS: usize> Add<Matrix<T, ROWS, COLS>> for Matrix<T, ROWS, COLS>
 where
-    [f64; ROWS * COLS]: Sized,
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
 {
     type Output = Self;
 
-    fn add(self, rhs: Matrix<ROWS, COLS>) -> Self::Output {
+    fn add(self, rhs: Matrix<T, ROWS, COLS>) -> Self::Output {
         let mut elems = self.elems;
-        for i in 0..self.elems.len() {
-            elems[i] += rhs.elems[i];
-        }
+        elems
+            .iter_mut()
+            .zip(rhs.elems)
+            .for_each(|(e, r)| *e = *e + r);
         Self::from_array(elems)
     }
 }
 
 // Implement matrix subtraction
-impl<const ROWS: usize, const COLS: usize> Sub<Matrix<ROWS, COLS>> for Matrix<ROWS, COLS>
+impl<T, const ROWS: usize, const COLS: usize> Sub<Matrix<T, ROWS, COLS>> for Matrix<T, ROWS, COLS>
 where
-    [f64; ROWS * COLS]: Sized,
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
 {
     type Output = Self;
 
-    fn sub(self, rhs: Matrix<ROWS, COLS>) -> Self::Output {
+    fn sub(self, rhs: Matrix<T, ROWS, COLS>) -> Self::Output {
         let mut elems = self.elems;
-        for i in 0..self.elems.len() {
-            elems[i] -= rhs.elems[i];
-        }
+        elems
+            .iter_mut()
+            .zip(rhs.elems)
+            .for_each(|(e, r)| *e = *e - r);
         Self::from_array(elems)
     }
 }
 
 // Implement matrix scalar multiplication
-impl<const ROWS: usize, const COLS: usize> Mul<f64> for Matrix<ROWS, COLS>
+impl<T, const ROWS: usize, const COLS: usize> Mul<T> for Matrix<T, ROWS, COLS>
 where
-    [f64; ROWS * COLS]: Sized,
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
 {
     type Output = Self;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Self {
             elems: self.elems.map(|elem| elem * rhs),
         }
@@ -224,27 +348,28 @@ where
 
 // Multiplication is implemented for matrices when the left matrix has the same number of columns as
 // the right side has rows. Now we have const generics to make this all compile-time!
-impl<const A_ROW: usize, const A_COL_B_ROW: usize, const B_COL: usize>
-    Mul<Matrix<A_COL_B_ROW, B_COL>> for Matrix<A_ROW, A_COL_B_ROW>
+impl<T, const A_ROW: usize, const A_COL_B_ROW: usize, const B_COL: usize>
+    Mul<Matrix<T, A_COL_B_ROW, B_COL>> for Matrix<T, A_ROW, A_COL_B_ROW>
 where
-    [f64; A_ROW * A_COL_B_ROW]: Sized,
-    [f64; A_COL_B_ROW * B_COL]: Sized,
-    [f64; A_ROW * B_COL]: Sized,
+    T: MatrixElement,
+    [T; A_ROW * A_COL_B_ROW]: Sized,
+    [T; A_COL_B_ROW * B_COL]: Sized,
+    [T; A_ROW * B_COL]: Sized,
 {
     // The output matrix will have the same number of rows as the left matrix and the same number of
     // columns as the right
-    type Output = Matrix<A_ROW, B_COL>;
+    type Output = Matrix<T, A_ROW, B_COL>;
 
-    fn mul(self, rhs: Matrix<A_COL_B_ROW, B_COL>) -> Self::Output {
+    fn mul(self, rhs: Matrix<T, A_COL_B_ROW, B_COL>) -> Self::Output {
         let mut output_matrix = Matrix::empty();
 
         // Loop through each row for each column in the output matrix
         for col in 0..B_COL {
             for row in 0..A_ROW {
                 // Sum up the products of the matrix values
-                let mut sum = 0.0;
+                let mut sum = T::zero();
                 for cell in 0..A_COL_B_ROW {
-                    sum += self[(row, cell)] * rhs[(cell, col)];
+                    sum = sum + self[(row, cell)] * rhs[(cell, col)];
                 }
                 // And set the output to this
                 output_matrix[(row, col)] = sum;
@@ -254,3 +379,414 @@ where
         output_matrix
     }
 }
+
+// Implement in-place matrix addition
+impl<T, const ROWS: usize, const COLS: usize> AddAssign<Matrix<T, ROWS, COLS>>
+    for Matrix<T, ROWS, COLS>
+where
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
+{
+    fn add_assign(&mut self, rhs: Matrix<T, ROWS, COLS>) {
+        for i in 0..self.elems.len() {
+            self.elems[i] = self.elems[i] + rhs.elems[i];
+        }
+    }
+}
+
+// Implement in-place matrix subtraction
+impl<T, const ROWS: usize, const COLS: usize> SubAssign<Matrix<T, ROWS, COLS>>
+    for Matrix<T, ROWS, COLS>
+where
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
+{
+    fn sub_assign(&mut self, rhs: Matrix<T, ROWS, COLS>) {
+        for i in 0..self.elems.len() {
+            self.elems[i] = self.elems[i] - rhs.elems[i];
+        }
+    }
+}
+
+// Implement in-place scalar multiplication
+impl<T, const ROWS: usize, const COLS: usize> MulAssign<T> for Matrix<T, ROWS, COLS>
+where
+    T: MatrixElement,
+    [T; ROWS * COLS]: Sized,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        for elem in self.elems.iter_mut() {
+            *elem = *elem * rhs;
+        }
+    }
+}
+
+// Implement scalar division
+impl<T, const ROWS: usize, const COLS: usize> Div<T> for Matrix<T, ROWS, COLS>
+where
+    T: MatrixElement + Div<Output = T>,
+    [T; ROWS * COLS]: Sized,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            elems: self.elems.map(|elem| elem / rhs),
+        }
+    }
+}
+
+// Implement in-place scalar division
+impl<T, const ROWS: usize, const COLS: usize> DivAssign<T> for Matrix<T, ROWS, COLS>
+where
+    T: MatrixElement + Div<Output = T>,
+    [T; ROWS * COLS]: Sized,
+{
+    fn div_assign(&mut self, rhs: T) {
+        for elem in self.elems.iter_mut() {
+            *elem = *elem / rhs;
+        }
+    }
+}
+
+// Implement unary negation, flipping the sign of every element
+impl<T, const ROWS: usize, const COLS: usize> Neg for Matrix<T, ROWS, COLS>
+where
+    T: MatrixElement + Neg<Output = T>,
+    [T; ROWS * COLS]: Sized,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            elems: self.elems.map(|elem| -elem),
+        }
+    }
+}
+
+// Square-matrix-only operations built on cofactor expansion. Unlike the LU-based determinant and
+// inverse below, these only need addition, subtraction, multiplication, and negation, so they work
+// for any signed `MatrixElement` (including integer types), at the cost of factorial-time blowup
+// for large `N`.
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: MatrixElement,
+    [T; N * N]: Sized,
+{
+    /// Return the submatrix formed by deleting row `del_row` and column `del_col` from this
+    /// matrix. Panics if this matrix is smaller than 2x2.
+    pub fn minor(&self, del_row: usize, del_col: usize) -> Matrix<T, { N - 1 }, { N - 1 }>
+    where
+        [T; { N - 1 } * { N - 1 }]: Sized,
+    {
+        assert!(N >= 2, "cannot take the minor of a matrix smaller than 2x2");
+
+        let mut minor = Matrix::<T, { N - 1 }, { N - 1 }>::empty();
+        let mut out_row = 0;
+        for row in 0..N {
+            if row == del_row {
+                continue;
+            }
+            let mut out_col = 0;
+            for col in 0..N {
+                if col == del_col {
+                    continue;
+                }
+                minor[(out_row, out_col)] = self[(row, col)];
+                out_col += 1;
+            }
+            out_row += 1;
+        }
+        minor
+    }
+
+    /// Compute the determinant of this matrix by cofactor expansion along its first row. Unlike
+    /// [`Matrix::determinant`], this never divides, so it is exact for integer and other
+    /// non-floating-point element types.
+    ///
+    /// This recurses on plain `Vec`s rather than on `Matrix<T, {N-1}, {N-1}>` because
+    /// `generic_const_exprs` has no way to prove `[T; {K}*{K}]: Sized` for every `K` reached by
+    /// shrinking `N` one row/column at a time — only for the one fixed `N - 1` named in this
+    /// function's own signature. Recursing at the value level sidesteps that limitation entirely.
+    pub fn cofactor_determinant(&self) -> T
+    where
+        T: Neg<Output = T>,
+    {
+        let rows: Vec<Vec<T>> = (0..N)
+            .map(|row| (0..N).map(|col| self[(row, col)]).collect())
+            .collect();
+        Self::cofactor_determinant_of(&rows)
+    }
+
+    /// Compute the `(i, j)` cofactor of this matrix: `(-1)^(i+j)` times the determinant of the
+    /// minor formed by deleting row `i` and column `j`.
+    pub fn cofactor(&self, i: usize, j: usize) -> T
+    where
+        T: Neg<Output = T>,
+    {
+        let rows: Vec<Vec<T>> = (0..N)
+            .map(|row| (0..N).map(|col| self[(row, col)]).collect())
+            .collect();
+        let minor_det = Self::cofactor_determinant_of(&Self::minor_of(&rows, i, j));
+        if (i + j).is_multiple_of(2) {
+            minor_det
+        } else {
+            -minor_det
+        }
+    }
+
+    /// Build the matrix of cofactors of this matrix, i.e. the matrix whose `(i, j)` element is
+    /// `self.cofactor(i, j)`.
+    pub fn cofactor_matrix(&self) -> Matrix<T, N, N>
+    where
+        T: Neg<Output = T>,
+    {
+        let mut cofactors = Matrix::<T, N, N>::empty();
+        for (row, col) in self.indices() {
+            cofactors[(row, col)] = self.cofactor(row, col);
+        }
+        cofactors
+    }
+
+    /// Compute the adjugate (classical adjoint) of this matrix: the transpose of its cofactor
+    /// matrix. `self * self.adjugate() == self.determinant() * Matrix::identity()`, which gives an
+    /// alternate, pivot-free path to the inverse as `adjugate / determinant`.
+    pub fn adjugate(&self) -> Matrix<T, N, N>
+    where
+        T: Neg<Output = T>,
+    {
+        self.cofactor_matrix().transpose()
+    }
+
+    // Compute the determinant of a square matrix given as rows of a `Vec`, by cofactor expansion
+    // along the first row. The recursion shrinks a runtime `Vec` rather than a type-level `N`, so
+    // it needs no `generic_const_exprs` support.
+    fn cofactor_determinant_of(rows: &[Vec<T>]) -> T
+    where
+        T: Neg<Output = T>,
+    {
+        if rows.len() == 1 {
+            return rows[0][0];
+        }
+
+        (0..rows.len()).fold(T::zero(), |sum, col| {
+            let term = rows[0][col] * Self::cofactor_determinant_of(&Self::minor_of(rows, 0, col));
+            if col.is_multiple_of(2) {
+                sum + term
+            } else {
+                sum - term
+            }
+        })
+    }
+
+    // Delete row `del_row` and column `del_col` from a square matrix given as rows of a `Vec`.
+    fn minor_of(rows: &[Vec<T>], del_row: usize, del_col: usize) -> Vec<Vec<T>> {
+        rows.iter()
+            .enumerate()
+            .filter(|(row, _)| *row != del_row)
+            .map(|(_, cols)| {
+                cols.iter()
+                    .enumerate()
+                    .filter(|(col, _)| *col != del_col)
+                    .map(|(_, &elem)| elem)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+// Norms, implemented for `f64` matrices since they involve square roots and other operations
+// that aren't meaningful for every `MatrixElement`.
+impl<const ROWS: usize, const COLS: usize> Matrix<f64, ROWS, COLS>
+where
+    [f64; ROWS * COLS]: Sized,
+{
+    /// Compute the Frobenius norm of this matrix: the square root of the sum of the squares of
+    /// its elements.
+    pub fn frobenius_norm(&self) -> f64 {
+        self.elems.iter().map(|elem| elem * elem).sum::<f64>().sqrt()
+    }
+
+    /// Compute the max-abs norm of this matrix: the largest absolute value among its elements.
+    pub fn max_abs_norm(&self) -> f64 {
+        self.elems.iter().fold(0.0, |max, elem| f64::max(max, elem.abs()))
+    }
+}
+
+// Vector-only norms and geometry, built on top of the Lp-norm family.
+#[allow(clippy::identity_op)]
+impl<const N: usize> Matrix<f64, N, 1>
+where
+    [f64; N * 1]: Sized,
+{
+    /// Compute the Lp-norm of this vector for the given `p`. `p = 1.0` gives the L1 (taxicab)
+    /// norm, `p = 2.0` gives the L2 (Euclidean) norm, and `p = f64::INFINITY` gives the L∞
+    /// (max-abs) norm.
+    pub fn lp_norm(&self, p: f64) -> f64 {
+        if p.is_infinite() {
+            self.max_abs_norm()
+        } else {
+            self.elems
+                .iter()
+                .map(|elem| elem.abs().powf(p))
+                .sum::<f64>()
+                .powf(1.0 / p)
+        }
+    }
+
+    /// Return the unit vector pointing in the same direction as this vector, or `None` if this
+    /// vector's norm is zero.
+    pub fn normalize(&self) -> Option<Matrix<f64, N, 1>> {
+        let norm = self.lp_norm(2.0);
+        if norm == 0.0 {
+            None
+        } else {
+            Some(*self / norm)
+        }
+    }
+
+    /// Compute the Euclidean distance between this vector and `other`.
+    pub fn distance(&self, other: &Self) -> f64 {
+        (*self - *other).lp_norm(2.0)
+    }
+}
+
+// Randomized constructors. These require the `rand` crate, so they are gated behind the `rand`
+// feature to keep the core crate dependency-free.
+#[cfg(feature = "rand")]
+impl<const ROWS: usize, const COLS: usize> Matrix<f64, ROWS, COLS>
+where
+    [f64; ROWS * COLS]: Sized,
+{
+    /// Create and return a matrix with every element drawn independently from a uniform
+    /// `[0, 1)` distribution.
+    pub fn random() -> Self {
+        Self::random_range(0.0, 1.0)
+    }
+
+    /// Create and return a matrix with every element drawn independently from a uniform
+    /// `[min, max)` distribution.
+    pub fn random_range(min: f64, max: f64) -> Self {
+        let mut rng = rand::thread_rng();
+        let mut matrix = Self::empty();
+        for col in 0..COLS {
+            for row in 0..ROWS {
+                matrix[(row, col)] = rng.gen_range(min..max);
+            }
+        }
+        matrix
+    }
+}
+
+// Square-matrix-only operations built on LU decomposition. These rely on floating-point division
+// and comparison, so they are implemented for `f64` matrices specifically rather than for every
+// `MatrixElement`.
+impl<const N: usize> Matrix<f64, N, N>
+where
+    [f64; N * N]: Sized,
+{
+    /// Factor this matrix into `P·A = L·U` using Gaussian elimination with partial pivoting.
+    ///
+    /// Returns `(L, U, pivots, sign)` where `L` is unit lower-triangular, `U` is upper-triangular,
+    /// `pivots[i]` is the row of this matrix that ended up at row `i` after pivoting, and `sign`
+    /// is `-1` or `1` depending on the parity of the row swaps performed. Returns `None` if any
+    /// column's chosen pivot has a magnitude below a small epsilon, i.e. the matrix is singular.
+    #[allow(clippy::type_complexity)]
+    pub fn lu(&self) -> Option<(Matrix<f64, N, N>, Matrix<f64, N, N>, [usize; N], i32)> {
+        const EPSILON: f64 = 1e-12;
+
+        let mut u = *self;
+        let mut l = Matrix::<f64, N, N>::identity();
+        let mut pivots = [0usize; N];
+        for (i, pivot) in pivots.iter_mut().enumerate() {
+            *pivot = i;
+        }
+        let mut sign = 1;
+
+        for k in 0..N {
+            // Find the row with the largest-magnitude entry in column k at or below row k.
+            let pivot_row = (k..N)
+                .max_by(|&a, &b| u[(a, k)].abs().total_cmp(&u[(b, k)].abs()))
+                .unwrap();
+
+            if u[(pivot_row, k)].abs() < EPSILON {
+                return None;
+            }
+
+            if pivot_row != k {
+                for col in 0..N {
+                    let tmp = u[(k, col)];
+                    u[(k, col)] = u[(pivot_row, col)];
+                    u[(pivot_row, col)] = tmp;
+                }
+                for col in 0..k {
+                    let tmp = l[(k, col)];
+                    l[(k, col)] = l[(pivot_row, col)];
+                    l[(pivot_row, col)] = tmp;
+                }
+                pivots.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            for i in (k + 1)..N {
+                let factor = u[(i, k)] / u[(k, k)];
+                l[(i, k)] = factor;
+                for col in k..N {
+                    u[(i, col)] -= factor * u[(k, col)];
+                }
+            }
+        }
+
+        Some((l, u, pivots, sign))
+    }
+
+    /// Compute the determinant of this matrix as `sign * Π U[i][i]` from its LU decomposition.
+    /// Returns `0.0` for a singular matrix.
+    pub fn determinant(&self) -> f64 {
+        match self.lu() {
+            Some((_, u, _, sign)) => sign as f64 * (0..N).map(|i| u[(i, i)]).product::<f64>(),
+            None => 0.0,
+        }
+    }
+
+    /// Compute the inverse of this matrix, or `None` if it is singular.
+    ///
+    /// For each column `e_k` of the identity matrix, solves `A·x = e_k` by forward-substituting
+    /// against `L` and back-substituting against `U`, then assembles the solutions into columns
+    /// of the result.
+    pub fn inverse(&self) -> Option<Matrix<f64, N, N>> {
+        let (l, u, pivots, _) = self.lu()?;
+        let mut inverse = Matrix::<f64, N, N>::empty();
+
+        for col in 0..N {
+            // `P·e_col`, i.e. the `col`-th identity column permuted into pivoted row order.
+            let permuted = pivots.map(|row| if row == col { 1.0 } else { 0.0 });
+
+            // Forward substitution solves `L·y = P·e_col` (L is unit lower-triangular).
+            let mut y = [0.0; N];
+            for i in 0..N {
+                let mut sum = permuted[i];
+                for j in 0..i {
+                    sum -= l[(i, j)] * y[j];
+                }
+                y[i] = sum;
+            }
+
+            // Back substitution solves `U·x = y`.
+            let mut x = [0.0; N];
+            for i in (0..N).rev() {
+                let mut sum = y[i];
+                for j in (i + 1)..N {
+                    sum -= u[(i, j)] * x[j];
+                }
+                x[i] = sum / u[(i, i)];
+            }
+
+            for (row, value) in x.into_iter().enumerate() {
+                inverse[(row, col)] = value;
+            }
+        }
+
+        Some(inverse)
+    }
+}